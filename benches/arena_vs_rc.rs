@@ -0,0 +1,88 @@
+use arboretum::search::{arena_bst::ArenaBst, bst::Bst};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+// A fixed, deterministic pseudo-random sequence so the two backings insert
+// and look up the exact same values in the exact same order.
+fn shuffled(n: usize) -> Vec<i64> {
+    let mut values: Vec<i64> = (0..n as i64).collect();
+    let mut state = 0x2545F4914F6CDD1Du64;
+
+    for i in (1..values.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        values.swap(i, (state as usize) % (i + 1));
+    }
+
+    values
+}
+
+fn bench_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert");
+
+    for size in SIZES {
+        let values = shuffled(size);
+
+        group.bench_with_input(BenchmarkId::new("rc_refcell", size), &values, |b, values| {
+            b.iter(|| {
+                let mut bst = Bst::empty();
+
+                for &value in values {
+                    bst.insert(black_box(value));
+                }
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("arena", size), &values, |b, values| {
+            b.iter(|| {
+                let mut bst = ArenaBst::empty();
+
+                for &value in values {
+                    bst.insert(black_box(value));
+                }
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_find(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find");
+
+    for size in SIZES {
+        let values = shuffled(size);
+
+        let mut rc_bst = Bst::empty();
+        let mut arena_bst = ArenaBst::empty();
+
+        for &value in &values {
+            rc_bst.insert(value);
+            arena_bst.insert(value);
+        }
+
+        group.bench_with_input(BenchmarkId::new("rc_refcell", size), &values, |b, values| {
+            b.iter(|| {
+                for &value in values {
+                    black_box(rc_bst.contains(value));
+                }
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("arena", size), &values, |b, values| {
+            b.iter(|| {
+                for &value in values {
+                    black_box(arena_bst.contains(value));
+                }
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_insert, bench_find);
+criterion_main!(benches);
@@ -0,0 +1,200 @@
+/// A binary search tree backed by a single `Vec<Node<V>>` arena instead of
+/// individually heap-allocated `Rc<RefCell<>>` nodes.
+///
+/// `left`/`right`/`parent` are `Option<usize>` indices into the arena rather
+/// than smart pointers, so there is no per-node allocation, no reference
+/// counting, and no runtime borrow checking on traversal. This also makes
+/// the whole tree trivially `Clone` and friendlier to the cache than a tree
+/// of scattered `Rc` allocations.
+///
+/// The public API mirrors [`super::bst::Bst`] (`empty`, `insert`,
+/// `contains`, `remove`); pick whichever backing fits the workload.
+///
+/// Removed nodes free their slot onto a free-list so repeated
+/// insert/remove churn does not grow the arena unboundedly; slots are
+/// reused on the next insert before the `Vec` is grown.
+#[derive(Clone)]
+pub struct ArenaBst<V: PartialOrd> {
+    nodes: Vec<Option<Node<V>>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+}
+
+#[derive(Clone)]
+struct Node<V: PartialOrd> {
+    parent: Option<usize>,
+    left: Option<usize>,
+    right: Option<usize>,
+    value: V,
+}
+
+impl<V: PartialOrd> ArenaBst<V> {
+    pub fn empty() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+        }
+    }
+
+    pub fn insert(&mut self, value: V) {
+        let root = match self.root {
+            Some(root) => root,
+            None => {
+                let idx = self.alloc(Node {
+                    parent: None,
+                    left: None,
+                    right: None,
+                    value,
+                });
+
+                self.root = Some(idx);
+                return;
+            }
+        };
+
+        let mut current = root;
+
+        loop {
+            if self.node(current).value <= value {
+                match self.node(current).left {
+                    Some(left) => current = left,
+                    None => {
+                        let idx = self.alloc(Node {
+                            parent: Some(current),
+                            left: None,
+                            right: None,
+                            value,
+                        });
+
+                        self.node_mut(current).left = Some(idx);
+                        return;
+                    }
+                }
+            } else {
+                match self.node(current).right {
+                    Some(right) => current = right,
+                    None => {
+                        let idx = self.alloc(Node {
+                            parent: Some(current),
+                            left: None,
+                            right: None,
+                            value,
+                        });
+
+                        self.node_mut(current).right = Some(idx);
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn contains(&self, value: V) -> bool {
+        self.find(value).is_some()
+    }
+
+    pub fn remove(&mut self, value: V) {
+        let z = match self.find(value) {
+            Some(z) => z,
+            None => return,
+        };
+
+        let z_left = self.node(z).left;
+        let z_right = self.node(z).right;
+
+        match (z_left, z_right) {
+            (None, _) => self.transplant(z, z_right),
+            (Some(_), None) => self.transplant(z, z_left),
+            (Some(z_left), Some(z_right)) => {
+                // `y` is the leftmost node of `z`'s right subtree (the
+                // greatest value smaller than `z`'s), same as the
+                // `Rc<RefCell<>>` backing's `deep_delete`.
+                let mut y = z_right;
+
+                while let Some(left) = self.node(y).left {
+                    y = left;
+                }
+
+                if self.node(y).parent != Some(z) {
+                    let y_right = self.node(y).right;
+                    self.transplant(y, y_right);
+
+                    self.node_mut(y).right = Some(z_right);
+                    self.node_mut(z_right).parent = Some(y);
+                }
+
+                self.transplant(z, Some(y));
+
+                self.node_mut(y).left = Some(z_left);
+                self.node_mut(z_left).parent = Some(y);
+            }
+        }
+
+        self.free_slot(z);
+    }
+
+    fn find(&self, value: V) -> Option<usize> {
+        let mut current = self.root;
+
+        while let Some(idx) = current {
+            let node = self.node(idx);
+
+            if node.value == value {
+                return Some(idx);
+            }
+
+            current = if node.value < value {
+                node.left
+            } else {
+                node.right
+            };
+        }
+
+        None
+    }
+
+    /// Transplants `v` into `u`'s position in the tree; `u`'s own children
+    /// are left untouched, the caller is responsible for moving them.
+    fn transplant(&mut self, u: usize, v: Option<usize>) {
+        let parent = self.node(u).parent;
+
+        match parent {
+            None => self.root = v,
+            Some(p) => {
+                if self.node(p).left == Some(u) {
+                    self.node_mut(p).left = v;
+                } else {
+                    self.node_mut(p).right = v;
+                }
+            }
+        }
+
+        if let Some(v) = v {
+            self.node_mut(v).parent = parent;
+        }
+    }
+
+    fn alloc(&mut self, node: Node<V>) -> usize {
+        if let Some(slot) = self.free.pop() {
+            self.nodes[slot] = Some(node);
+            slot
+        } else {
+            self.nodes.push(Some(node));
+            self.nodes.len() - 1
+        }
+    }
+
+    fn free_slot(&mut self, idx: usize) {
+        self.nodes[idx] = None;
+        self.free.push(idx);
+    }
+
+    fn node(&self, idx: usize) -> &Node<V> {
+        self.nodes[idx].as_ref().expect("dangling arena index")
+    }
+
+    fn node_mut(&mut self, idx: usize) -> &mut Node<V> {
+        self.nodes[idx].as_mut().expect("dangling arena index")
+    }
+}
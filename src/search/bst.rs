@@ -14,6 +14,8 @@ struct Node<V: PartialOrd> {
     left: MaybeLink<V>,
     right: MaybeLink<V>,
     value: V,
+    /// Size of the subtree rooted at this node, itself included.
+    size: usize,
 }
 
 impl<V: PartialOrd> Node<V> {
@@ -23,6 +25,7 @@ impl<V: PartialOrd> Node<V> {
             left: None,
             right: None,
             value,
+            size: 1,
         }))
     }
 
@@ -32,10 +35,16 @@ impl<V: PartialOrd> Node<V> {
             left: None,
             right: None,
             value,
+            size: 1,
         }))
     }
 }
 
+/// Size of the subtree rooted at `link`, or `0` for an empty link.
+fn size<V: PartialOrd>(link: &MaybeLink<V>) -> usize {
+    link.as_ref().map_or(0, |node| node.borrow().size)
+}
+
 /// A binary search tree.
 ///
 /// Assuming I've implemented it correctly it will:
@@ -89,11 +98,88 @@ impl<V: PartialOrd> Bst<V> {
             Some(_) => deep_delete(self, value),
         };
     }
+
+    /// Returns an iterator over the values stored in this tree, in
+    /// ascending order.
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter::new(&self.root)
+    }
+
+    /// Returns the `k`-th smallest value in the tree (zero-indexed), or
+    /// `None` if the tree holds `k` or fewer values.
+    pub fn select(&self, k: usize) -> Option<&V> {
+        match self.root {
+            None => None,
+            Some(ref root) => deep_select(root, k),
+        }
+    }
+
+    /// Returns how many stored values are strictly less than `value`.
+    pub fn rank(&self, value: &V) -> usize {
+        self.count_less(value)
+    }
+
+    /// Returns how many stored values are strictly less than `value`.
+    pub fn count_less(&self, value: &V) -> usize {
+        deep_count_less(&self.root, value)
+    }
+
+    /// Splits this tree into two: one holding every value less than
+    /// `pivot`, the other holding every value greater than or equal to it.
+    ///
+    /// Runs in O(height) by cutting and reattaching subtrees along the
+    /// path to `pivot` rather than reinserting every value.
+    pub fn split(self, pivot: &V) -> (Bst<V>, Bst<V>) {
+        let (less, geq) = deep_split(self.root, pivot);
+
+        (Bst { root: less }, Bst { root: geq })
+    }
+
+    /// Merges `other` into this tree.
+    ///
+    /// Every value in `other` must be greater than every value already in
+    /// this tree; the two key ranges are assumed disjoint and are not
+    /// checked. Runs in O(height) by grafting `other`'s root onto this
+    /// tree's largest-valued node rather than reinserting every value.
+    pub fn merge(&mut self, other: Bst<V>) {
+        let other_root = match other.root {
+            Some(root) => root,
+            None => return,
+        };
+
+        let self_root = match self.root {
+            Some(ref root) => Rc::clone(root),
+            None => {
+                self.root = Some(other_root);
+                return;
+            }
+        };
+
+        // This tree's left spine holds its greatest values; the grafting
+        // point is the last node along that spine, i.e. its max.
+        let mut largest = self_root;
+
+        loop {
+            let left = largest.borrow().left.clone();
+
+            match left {
+                Some(left) => largest = left,
+                None => break,
+            }
+        }
+
+        other_root.borrow_mut().parent = Some(Rc::downgrade(&largest));
+        largest.borrow_mut().left = Some(other_root);
+
+        recompute_sizes_upward(Some(largest));
+    }
 }
 
 fn deep_insert<V: PartialOrd>(node: &Link<V>, value: V) {
     let mut mut_node = node.borrow_mut();
 
+    mut_node.size += 1;
+
     if mut_node.value <= value {
         if let Some(ref left) = mut_node.left {
             deep_insert(left, value);
@@ -130,64 +216,314 @@ fn deep_find<V: PartialOrd>(node: &Link<V>, value: V) -> MaybeLink<V> {
 }
 
 fn deep_contains<V: PartialOrd>(node: &Link<V>, value: V) -> bool {
-    let maybe_node = deep_find(node, value);
+    deep_find(node, value).is_some()
+}
 
-    match maybe_node {
-        None => false,
-        Some(_) => true,
+// Values ascend right-to-left in this tree (the left child holds values
+// greater than or equal to its parent, the right child holds lesser
+// values), so the `k`-th smallest value is found by weighing `k` against
+// the size of the *right* subtree rather than the left one.
+fn deep_select<V: PartialOrd>(node: &Link<V>, k: usize) -> Option<&V> {
+    // SAFETY: same invariant as `Iter::next` below — this only ever reads
+    // node data, and the returned reference cannot outlive the `&self`
+    // borrow that produced the `node: &Link<V>` we started from.
+    let node: &Node<V> = unsafe { &*node.as_ptr() };
+    let right_size = size(&node.right);
+
+    match k.cmp(&right_size) {
+        std::cmp::Ordering::Less => deep_select(node.right.as_ref()?, k),
+        std::cmp::Ordering::Equal => Some(&node.value),
+        std::cmp::Ordering::Greater => deep_select(node.left.as_ref()?, k - right_size - 1),
+    }
+}
+
+fn deep_count_less<V: PartialOrd>(node: &MaybeLink<V>, value: &V) -> usize {
+    match node {
+        None => 0,
+        Some(node) => {
+            let node_ref = node.borrow();
+
+            if node_ref.value < *value {
+                1 + size(&node_ref.right) + deep_count_less(&node_ref.left, value)
+            } else {
+                deep_count_less(&node_ref.right, value)
+            }
+        }
     }
 }
 
-fn deep_delete<V: PartialOrd>(bst: &mut Bst<V>, value: V) {}
+// As in `deep_select`/`deep_count_less`, the left child holds values
+// greater than or equal to its parent and the right child holds lesser
+// values. A node smaller than `pivot` therefore keeps its whole right
+// subtree (all smaller still) and only its left subtree can straddle the
+// pivot, so splitting recurses into exactly one side at each node.
+fn deep_split<V: PartialOrd>(node: MaybeLink<V>, pivot: &V) -> (MaybeLink<V>, MaybeLink<V>) {
+    let node = match node {
+        None => return (None, None),
+        Some(node) => node,
+    };
 
-fn shift_nodes<V: PartialOrd>(bst: &mut Bst<V>, node_a: &mut Link<V>, o_node_b: &mut MaybeLink<V>) {
-    let m_node_a = node_a.borrow_mut();
+    if node.borrow().value < *pivot {
+        let left = node.borrow_mut().left.take();
+        let (less, geq) = deep_split(left, pivot);
 
-    if let None = m_node_a.parent {
-        match o_node_b {
-            None => bst.root.take(),
-            Some(ref r_node_b) => bst.root.replace(Rc::clone(r_node_b)),
-        };
+        if let Some(ref less) = less {
+            less.borrow_mut().parent = Some(Rc::downgrade(&node));
+        }
+
+        node.borrow_mut().left = less;
+        recompute_size(&node);
+        node.borrow_mut().parent = None;
+
+        if let Some(ref geq) = geq {
+            geq.borrow_mut().parent = None;
+        }
+
+        (Some(node), geq)
+    } else {
+        let right = node.borrow_mut().right.take();
+        let (less, geq) = deep_split(right, pivot);
+
+        if let Some(ref geq) = geq {
+            geq.borrow_mut().parent = Some(Rc::downgrade(&node));
+        }
 
-        return;
+        node.borrow_mut().right = geq;
+        recompute_size(&node);
+        node.borrow_mut().parent = None;
+
+        if let Some(ref less) = less {
+            less.borrow_mut().parent = None;
+        }
+
+        (less, Some(node))
     }
+}
+
+fn recompute_size<V: PartialOrd>(node: &Link<V>) {
+    let mut node_mut = node.borrow_mut();
+    node_mut.size = size(&node_mut.left) + size(&node_mut.right) + 1;
+}
+
+fn deep_delete<V: PartialOrd>(bst: &mut Bst<V>, value: V) {
+    let z = match bst.root {
+        Some(ref root) => deep_find(root, value),
+        None => None,
+    };
 
-    let w_node_a_parent = m_node_a.parent.as_ref().unwrap();
-    let o_node_a_parent = w_node_a_parent.upgrade();
+    let z = match z {
+        Some(z) => z,
+        None => return,
+    };
+
+    let z_left = z.borrow().left.clone();
+    let z_right = z.borrow().right.clone();
+    let z_parent = z.borrow().parent.clone().and_then(|weak| weak.upgrade());
+
+    let size_fix_start;
+
+    if z_left.is_none() {
+        shift_nodes(bst, &z, &z_right);
+        size_fix_start = z_parent;
+    } else if z_right.is_none() {
+        shift_nodes(bst, &z, &z_left);
+        size_fix_start = z_parent;
+    } else {
+        // `y` is the leftmost node of `z`'s right subtree, which holds the
+        // greatest value smaller than `z`'s: the structural successor that
+        // can take `z`'s place without violating the tree.
+        let mut y = Rc::clone(z_right.as_ref().unwrap());
+
+        loop {
+            let next = y.borrow().left.clone();
+
+            match next {
+                Some(left) => y = left,
+                None => break,
+            }
+        }
 
-    if let None = o_node_a_parent {
-        panic!("Weird Bst structure detected. Found an orphan node");
+        let y_is_z_right_child = Rc::ptr_eq(&y, z_right.as_ref().unwrap());
+        let y_parent = y.borrow().parent.clone().and_then(|weak| weak.upgrade());
+
+        if !y_is_z_right_child {
+            let y_right = y.borrow().right.clone();
+            shift_nodes(bst, &y, &y_right);
+
+            let z_right_node = z_right.clone().unwrap();
+            y.borrow_mut().right = Some(Rc::clone(&z_right_node));
+            z_right_node.borrow_mut().parent = Some(Rc::downgrade(&y));
+        }
+
+        shift_nodes(bst, &z, &Some(Rc::clone(&y)));
+
+        let z_left_node = z_left.clone().unwrap();
+        y.borrow_mut().left = Some(Rc::clone(&z_left_node));
+        z_left_node.borrow_mut().parent = Some(Rc::downgrade(&y));
+
+        size_fix_start = if y_is_z_right_child { Some(y) } else { y_parent };
     }
 
-    let node_a_parent = o_node_a_parent.unwrap();
-    let mut m_node_a_parent = node_a_parent.borrow_mut();
+    // The size of every node between `size_fix_start` and the root has
+    // changed now that a node has left the tree; walk back up recomputing
+    // them from their (already correct) children rather than tracking
+    // deltas through every transplant above.
+    recompute_sizes_upward(size_fix_start);
+}
 
-    if let Some(ref r_node_a_parent_left) = m_node_a_parent.left {
-        // Node A is at the left of Node A Parent
-        if Rc::ptr_eq(node_a, r_node_a_parent_left) {
-            // We replace Node A on Node A Parent left with Node B
-            match o_node_b {
-                None => m_node_a_parent.left.take(),
-                Some(ref r_node_b) => m_node_a_parent.left.replace(Rc::clone(r_node_b)),
-            };
+fn recompute_sizes_upward<V: PartialOrd>(start: MaybeLink<V>) {
+    let mut current = start;
+
+    while let Some(node) = current {
+        recompute_size(&node);
+        current = node.borrow().parent.clone().and_then(|weak| weak.upgrade());
+    }
+}
+
+/// Transplants `node_b` into `node_a`'s position in the tree: if `node_a`
+/// is the root, `node_b` becomes the new root, otherwise `node_a`'s parent
+/// has its matching child pointer rewritten, and `node_b`'s parent link is
+/// updated to match. `node_a`'s own children are left untouched; the caller
+/// is responsible for moving them.
+fn shift_nodes<V: PartialOrd>(bst: &mut Bst<V>, node_a: &Link<V>, o_node_b: &MaybeLink<V>) {
+    let parent = node_a.borrow().parent.clone();
+
+    let parent = match parent {
+        None => {
+            bst.root = o_node_b.clone();
+
+            if let Some(node_b) = o_node_b {
+                node_b.borrow_mut().parent = None;
+            }
 
             return;
         }
+        Some(ref weak_parent) => weak_parent
+            .upgrade()
+            .unwrap_or_else(|| panic!("Weird Bst structure detected. Found an orphan node")),
+    };
+
+    let is_left = matches!(&parent.borrow().left, Some(left) if Rc::ptr_eq(left, node_a));
+    let is_right = matches!(&parent.borrow().right, Some(right) if Rc::ptr_eq(right, node_a));
+
+    if is_left {
+        parent.borrow_mut().left = o_node_b.clone();
+    } else if is_right {
+        parent.borrow_mut().right = o_node_b.clone();
     }
-    // Node A is at the right of Node A Parent
-    else {
-        // We replace Node A on Node A Parent right with Node B
-        match o_node_b {
-            None => m_node_a_parent.right.take(),
-            Some(ref r_node_b) => m_node_a_parent.right.replace(Rc::clone(r_node_b)),
-        };
 
-        return;
+    if let Some(node_b) = o_node_b {
+        node_b.borrow_mut().parent = Some(Rc::downgrade(&parent));
+    }
+}
+
+/// Borrowing in-order iterator over a [`Bst`]'s values, ascending.
+///
+/// Built from an explicit stack rather than recursion so it can be driven
+/// lazily through [`Iterator::next`]. Because values live inside this
+/// tree's own node ordering (the left child holds values greater than or
+/// equal to its parent, the right child holds lesser values), ascending
+/// order is produced by descending the *right* spine first.
+pub struct Iter<'a, V: PartialOrd> {
+    stack: Vec<Link<V>>,
+    _marker: std::marker::PhantomData<&'a V>,
+}
+
+impl<'a, V: PartialOrd> Iter<'a, V> {
+    fn new(root: &MaybeLink<V>) -> Self {
+        let mut stack = Vec::new();
+        push_right_spine(root, &mut stack);
+
+        Self {
+            stack,
+            _marker: std::marker::PhantomData,
+        }
     }
+}
+
+fn push_right_spine<V: PartialOrd>(node: &MaybeLink<V>, stack: &mut Vec<Link<V>>) {
+    let mut current = node.clone();
+
+    while let Some(node) = current {
+        current = node.borrow().right.clone();
+        stack.push(node);
+    }
+}
+
+impl<'a, V: PartialOrd> Iterator for Iter<'a, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let left = node.borrow().left.clone();
+        push_right_spine(&left, &mut self.stack);
+
+        // SAFETY: the `Rc` popped off the stack keeps its `Node` alive for
+        // as long as this `Iter` holds a reference to it (either on the
+        // stack or, transitively, through a still-stacked ancestor), which
+        // cannot outlive the `'a` borrow of the tree this iterator came
+        // from. No other code can mutate the tree while that borrow is
+        // held, so handing out `&'a V` here is sound despite the `RefCell`.
+        Some(unsafe { &*std::ptr::addr_of!((*node.as_ptr()).value) })
+    }
+}
+
+impl<'a, V: PartialOrd> IntoIterator for &'a Bst<V> {
+    type Item = &'a V;
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Owning in-order iterator over a [`Bst`]'s values, ascending.
+pub struct IntoIter<V: PartialOrd> {
+    stack: Vec<Link<V>>,
+}
+
+fn push_right_spine_owned<V: PartialOrd>(mut current: MaybeLink<V>, stack: &mut Vec<Link<V>>) {
+    while let Some(node) = current {
+        current = node.borrow_mut().right.take();
+        stack.push(node);
+    }
+}
+
+impl<V: PartialOrd> Iterator for IntoIter<V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let left = node.borrow_mut().left.take();
+        push_right_spine_owned(left, &mut self.stack);
+
+        let node = Rc::try_unwrap(node)
+            .unwrap_or_else(|_| panic!("Bst node unexpectedly shared while consuming the tree"));
+
+        Some(node.into_inner().value)
+    }
+}
+
+impl<V: PartialOrd> IntoIterator for Bst<V> {
+    type Item = V;
+    type IntoIter = IntoIter<V>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        let mut stack = Vec::new();
+        push_right_spine_owned(self.root.take(), &mut stack);
+
+        IntoIter { stack }
+    }
+}
+
+impl<V: PartialOrd> FromIterator<V> for Bst<V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut bst = Bst::empty();
+
+        for value in iter {
+            bst.insert(value);
+        }
 
-    // Now we fix Node B by changing Node B Parent to Node A Parent
-    if let Some(ref r_node_b) = o_node_b {
-        let mut m_node_b = r_node_b.borrow_mut();
-        m_node_b.parent.replace(Rc::downgrade(&node_a_parent));
+        bst
     }
 }
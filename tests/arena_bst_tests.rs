@@ -0,0 +1,114 @@
+use arboretum::search::arena_bst::ArenaBst;
+
+#[test]
+fn arena_bst_does_contain_item_added_to_it() {
+    let bst = &mut ArenaBst::empty();
+
+    bst.insert(5);
+
+    assert_eq!(true, bst.contains(5));
+}
+
+#[test]
+fn arena_bst_does_not_contain_item_removed_from_it() {
+    let bst = &mut ArenaBst::empty();
+
+    bst.insert(5);
+
+    assert_eq!(true, bst.contains(5));
+
+    bst.remove(5);
+
+    assert_eq!(false, bst.contains(5));
+}
+
+#[test]
+fn arena_bst_removes_leaf_node_with_no_children() {
+    let bst = &mut ArenaBst::empty();
+
+    bst.insert(5);
+    bst.insert(3);
+
+    bst.remove(3);
+
+    assert_eq!(false, bst.contains(3));
+    assert_eq!(true, bst.contains(5));
+}
+
+#[test]
+fn arena_bst_removes_node_with_one_child() {
+    let bst = &mut ArenaBst::empty();
+
+    bst.insert(5);
+    bst.insert(3);
+    bst.insert(2);
+
+    bst.remove(3);
+
+    assert_eq!(false, bst.contains(3));
+    assert_eq!(true, bst.contains(5));
+    assert_eq!(true, bst.contains(2));
+}
+
+#[test]
+fn arena_bst_removes_node_with_two_children_whose_successor_is_a_direct_child() {
+    let bst = &mut ArenaBst::empty();
+
+    for value in [50, 30, 70, 20, 40, 60, 80] {
+        bst.insert(value);
+    }
+
+    bst.remove(30);
+
+    assert_eq!(false, bst.contains(30));
+
+    for value in [50, 70, 20, 40, 60, 80] {
+        assert_eq!(true, bst.contains(value));
+    }
+}
+
+#[test]
+fn arena_bst_removes_root_with_two_children_whose_successor_is_nested() {
+    let bst = &mut ArenaBst::empty();
+
+    for value in [50, 30, 70, 20, 40, 60, 80] {
+        bst.insert(value);
+    }
+
+    bst.remove(50);
+
+    assert_eq!(false, bst.contains(50));
+
+    for value in [30, 70, 20, 40, 60, 80] {
+        assert_eq!(true, bst.contains(value));
+    }
+}
+
+#[test]
+fn arena_bst_recycles_freed_slots_across_insert_remove_churn() {
+    let bst = &mut ArenaBst::empty();
+
+    for value in 0..200 {
+        bst.insert(value);
+    }
+
+    for value in 0..200 {
+        if value % 2 == 0 {
+            bst.remove(value);
+        }
+    }
+
+    for value in 0..200 {
+        assert_eq!(value % 2 != 0, bst.contains(value));
+    }
+
+    // Re-inserting the removed values should recycle the freed slots
+    // rather than growing the arena without bound.
+    for value in (0..200).step_by(2) {
+        bst.insert(value);
+    }
+
+    for value in 0..200 {
+        assert_eq!(true, bst.contains(value));
+    }
+}
@@ -21,3 +21,201 @@ fn bst_does_not_contain_item_removed_from_it() {
 
     assert_eq!(false, bst.contains(5));
 }
+
+#[test]
+fn bst_removes_leaf_node_with_no_children() {
+    let bst = &mut Bst::empty();
+
+    bst.insert(5);
+    bst.insert(3);
+
+    bst.remove(3);
+
+    assert_eq!(false, bst.contains(3));
+    assert_eq!(true, bst.contains(5));
+}
+
+#[test]
+fn bst_removes_node_with_one_child() {
+    let bst = &mut Bst::empty();
+
+    bst.insert(5);
+    bst.insert(3);
+    bst.insert(2);
+
+    bst.remove(3);
+
+    assert_eq!(false, bst.contains(3));
+    assert_eq!(true, bst.contains(5));
+    assert_eq!(true, bst.contains(2));
+}
+
+#[test]
+fn bst_removes_node_with_two_children_whose_successor_is_a_direct_child() {
+    let bst = &mut Bst::empty();
+
+    for value in [50, 30, 70, 20, 40, 60, 80] {
+        bst.insert(value);
+    }
+
+    bst.remove(30);
+
+    assert_eq!(false, bst.contains(30));
+
+    for value in [50, 70, 20, 40, 60, 80] {
+        assert_eq!(true, bst.contains(value));
+    }
+}
+
+#[test]
+fn bst_removes_root_with_two_children_whose_successor_is_nested() {
+    let bst = &mut Bst::empty();
+
+    for value in [50, 30, 70, 20, 40, 60, 80] {
+        bst.insert(value);
+    }
+
+    bst.remove(50);
+
+    assert_eq!(false, bst.contains(50));
+
+    for value in [30, 70, 20, 40, 60, 80] {
+        assert_eq!(true, bst.contains(value));
+    }
+}
+
+#[test]
+fn bst_iter_yields_values_in_ascending_order() {
+    let bst = &mut Bst::empty();
+
+    for value in [5, 3, 8, 1, 4, 7, 9] {
+        bst.insert(value);
+    }
+
+    let sorted: Vec<&i32> = bst.iter().collect();
+
+    assert_eq!(vec![&1, &3, &4, &5, &7, &8, &9], sorted);
+}
+
+#[test]
+fn bst_into_iter_yields_values_in_ascending_order() {
+    let mut bst = Bst::empty();
+
+    for value in [5, 3, 8, 1, 4, 7, 9] {
+        bst.insert(value);
+    }
+
+    let sorted: Vec<i32> = bst.into_iter().collect();
+
+    assert_eq!(vec![1, 3, 4, 5, 7, 8, 9], sorted);
+}
+
+#[test]
+fn bst_collects_from_an_iterator_via_from_iter() {
+    let bst: Bst<i32> = vec![3, 1, 2].into_iter().collect();
+
+    let sorted: Vec<&i32> = bst.iter().collect();
+
+    assert_eq!(vec![&1, &2, &3], sorted);
+}
+
+#[test]
+fn bst_select_returns_kth_smallest_value_against_a_sorted_model() {
+    let values = [50, 30, 70, 20, 40, 60, 80];
+
+    let bst = &mut Bst::empty();
+
+    for value in values {
+        bst.insert(value);
+    }
+
+    let mut sorted = values;
+    sorted.sort();
+
+    for (k, value) in sorted.iter().enumerate() {
+        assert_eq!(Some(value), bst.select(k));
+    }
+
+    assert_eq!(None, bst.select(sorted.len()));
+}
+
+#[test]
+fn bst_rank_counts_values_strictly_less_than_given_value() {
+    let bst = &mut Bst::empty();
+
+    for value in [50, 30, 70, 20, 40, 60, 80] {
+        bst.insert(value);
+    }
+
+    assert_eq!(0, bst.rank(&20));
+    assert_eq!(3, bst.rank(&50));
+    assert_eq!(7, bst.rank(&100));
+}
+
+#[test]
+fn bst_count_less_agrees_with_rank() {
+    let bst = &mut Bst::empty();
+
+    for value in [50, 30, 70, 20, 40, 60, 80] {
+        bst.insert(value);
+    }
+
+    for value in [10, 20, 45, 80, 100] {
+        assert_eq!(bst.rank(&value), bst.count_less(&value));
+    }
+}
+
+#[test]
+fn bst_split_at_interior_pivot_partitions_values() {
+    let bst = Bst::from_iter([50, 30, 70, 20, 40, 60, 80]);
+
+    let (less, geq) = bst.split(&50);
+
+    assert_eq!(
+        vec![&20, &30, &40],
+        less.iter().collect::<Vec<&i32>>()
+    );
+    assert_eq!(
+        vec![&50, &60, &70, &80],
+        geq.iter().collect::<Vec<&i32>>()
+    );
+}
+
+#[test]
+fn bst_split_with_pivot_below_minimum_yields_empty_less_tree() {
+    let bst = Bst::from_iter([50, 30, 70, 20, 40, 60, 80]);
+
+    let (less, geq) = bst.split(&10);
+
+    assert_eq!(Vec::<&i32>::new(), less.iter().collect::<Vec<&i32>>());
+    assert_eq!(
+        vec![&20, &30, &40, &50, &60, &70, &80],
+        geq.iter().collect::<Vec<&i32>>()
+    );
+}
+
+#[test]
+fn bst_split_with_pivot_above_maximum_yields_empty_geq_tree() {
+    let bst = Bst::from_iter([50, 30, 70, 20, 40, 60, 80]);
+
+    let (less, geq) = bst.split(&90);
+
+    assert_eq!(
+        vec![&20, &30, &40, &50, &60, &70, &80],
+        less.iter().collect::<Vec<&i32>>()
+    );
+    assert_eq!(Vec::<&i32>::new(), geq.iter().collect::<Vec<&i32>>());
+}
+
+#[test]
+fn bst_merge_combines_two_disjoint_trees() {
+    let mut low = Bst::from_iter([20, 10, 30]);
+    let high = Bst::from_iter([70, 60, 80]);
+
+    low.merge(high);
+
+    assert_eq!(
+        vec![&10, &20, &30, &60, &70, &80],
+        low.iter().collect::<Vec<&i32>>()
+    );
+}